@@ -1,21 +1,32 @@
 use crate::{
-    Connection, ConnectionOptions, DFResult, RemoteSchemaRef, Transform, TransformStream,
-    transform_schema,
+    Connection, ConnectionOptions, DFResult, PartitioningScheme, RemoteSchemaRef, Transform,
+    TransformStream, transform_schema,
 };
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::compute::can_cast_types;
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::common::Column;
 use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{plan_datafusion_err, plan_err};
 use datafusion::execution::{SendableRecordBatchStream, TaskContext};
 use datafusion::physical_expr::{EquivalenceProperties, Partitioning};
 use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::metrics::{
+    BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet, Time,
+};
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::common::stats::Precision;
 use datafusion::physical_plan::{
-    DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties, project_schema,
+    DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties, RecordBatchStream, Statistics,
+    project_schema,
 };
 use datafusion::prelude::Expr;
-use futures::TryStreamExt;
+use futures::{Stream, TryStreamExt};
+use prost::Message;
 use std::any::Any;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 #[derive(Debug)]
 pub struct RemoteTableExec {
@@ -29,6 +40,19 @@ pub struct RemoteTableExec {
     pub(crate) transform: Option<Arc<dyn Transform>>,
     conn: Arc<dyn Connection>,
     plan_properties: PlanProperties,
+    /// One pushed-down SQL statement per partition. Holds just `[sql]` when the
+    /// connection has no partitioning scheme configured, or `support_rewrite_with_filters_limit`
+    /// rejects splitting this particular statement.
+    partition_sqls: Vec<String>,
+    metrics: ExecutionPlanMetricsSet,
+    /// Name under which `transform` was registered with a [`TransformRegistry`], if any.
+    /// [`RemoteTableExecCodec`] needs this to re-resolve the transform on the worker that
+    /// decodes the plan, since a `Arc<dyn Transform>` itself cannot be shipped over the wire.
+    transform_name: Option<String>,
+    /// Populated on first call to `statistics()`: with the probed row count when `conn_options`
+    /// has the remote statistics probe enabled and the probe succeeds, otherwise with unknown
+    /// statistics. Repeated optimizer calls therefore don't re-hit the database.
+    statistics_cache: std::sync::OnceLock<Statistics>,
 }
 
 impl RemoteTableExec {
@@ -50,9 +74,10 @@ impl RemoteTableExec {
             remote_schema.as_ref(),
         )?;
         let projected_schema = project_schema(&transformed_table_schema, projection.as_ref())?;
+        let partition_sqls = build_partition_sqls(&conn_options, &sql)?;
         let plan_properties = PlanProperties::new(
             EquivalenceProperties::new(projected_schema),
-            Partitioning::UnknownPartitioning(1),
+            Partitioning::UnknownPartitioning(partition_sqls.len()),
             EmissionType::Incremental,
             Boundedness::Bounded,
         );
@@ -67,8 +92,122 @@ impl RemoteTableExec {
             transform,
             conn,
             plan_properties,
+            partition_sqls,
+            metrics: ExecutionPlanMetricsSet::new(),
+            transform_name: None,
+            statistics_cache: std::sync::OnceLock::new(),
         })
     }
+
+    /// Records the name `transform` was registered under in a [`TransformRegistry`], enabling
+    /// this plan to round-trip through [`RemoteTableExecCodec`]. Plans built from an
+    /// ad hoc, unregistered `Transform` can still execute locally but cannot be shipped to a
+    /// remote worker.
+    pub fn with_transform_name(mut self, name: impl Into<String>) -> Self {
+        self.transform_name = Some(name.into());
+        self
+    }
+}
+
+/// Rewrites `sql` into one disjoint query per partition according to the connection's
+/// [`PartitioningScheme`], falling back to a single partition when no scheme is configured
+/// or `support_rewrite_with_filters_limit` deems `sql` unsafe to split (e.g. it already
+/// contains a `GROUP BY` or is otherwise not a plain row scan).
+fn build_partition_sqls(conn_options: &ConnectionOptions, sql: &str) -> DFResult<Vec<String>> {
+    if !conn_options.db_type().support_rewrite_with_filters_limit(sql) {
+        return Ok(vec![sql.to_string()]);
+    }
+
+    match conn_options.partitioning_scheme() {
+        Some(PartitioningScheme::Range {
+            column,
+            partition_count,
+            min,
+            max,
+        }) if *partition_count > 1 => Ok(range_partition_bounds(*min, *max, *partition_count)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (lo, hi))| {
+                format!(
+                    "SELECT * FROM ({sql}) remote_table_partition_{i} WHERE {column} >= {lo} AND {column} < {hi}"
+                )
+            })
+            .collect()),
+        Some(PartitioningScheme::LimitOffset {
+            partition_count,
+            rows_per_partition,
+        }) if *partition_count > 1 => Ok((0..*partition_count)
+            .map(|i| {
+                let offset = i * rows_per_partition;
+                if i + 1 == *partition_count {
+                    format!("SELECT * FROM ({sql}) remote_table_partition_{i} OFFSET {offset}")
+                } else {
+                    format!(
+                        "SELECT * FROM ({sql}) remote_table_partition_{i} LIMIT {rows_per_partition} OFFSET {offset}"
+                    )
+                }
+            })
+            .collect()),
+        _ => Ok(vec![sql.to_string()]),
+    }
+}
+
+/// Computes `[lo, hi)` bounds for each of up to `partition_count` range-split buckets over
+/// `[min, max]` inclusive.
+///
+/// The column only has `max - min + 1` distinct integer values to spread across partitions.
+/// Requesting more partitions than that would make the per-partition span truncate to 0 via
+/// integer division, giving every partition but the last an empty, self-contradictory
+/// `lo >= x AND x < lo` predicate and dumping the whole result set on the last partition.
+/// This clamps the returned bucket count to the range width so every bucket spans at least one
+/// value, which is why callers must re-derive the effective partition count from `len()`
+/// rather than assuming it equals the requested `partition_count`.
+fn range_partition_bounds(min: i64, max: i64, partition_count: usize) -> Vec<(i64, i64)> {
+    let range_width = (max - min + 1).max(1);
+    let effective_partition_count = (partition_count as i64).min(range_width).max(1) as usize;
+    let span = range_width / effective_partition_count as i64;
+    (0..effective_partition_count)
+        .map(|i| {
+            let lo = min + span * i as i64;
+            let hi = if i + 1 == effective_partition_count {
+                max + 1
+            } else {
+                min + span * (i as i64 + 1)
+            };
+            (lo, hi)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod partition_bounds_tests {
+    use super::*;
+
+    #[test]
+    fn splits_evenly_when_range_covers_partitions() {
+        let bounds = range_partition_bounds(0, 99, 4);
+        assert_eq!(bounds, vec![(0, 25), (25, 50), (50, 75), (75, 100)]);
+    }
+
+    #[test]
+    fn clamps_partition_count_to_a_narrower_range_instead_of_collapsing_spans() {
+        // Only 6 distinct values (0..=5) but 10 partitions requested: every bucket must still
+        // be non-degenerate, so the bucket count is clamped down rather than producing
+        // `lo == hi` buckets that silently route everything to the last partition.
+        let bounds = range_partition_bounds(0, 5, 10);
+        assert_eq!(bounds.len(), 6);
+        for (lo, hi) in &bounds {
+            assert!(hi > lo, "bucket [{lo}, {hi}) must not be empty");
+        }
+        assert_eq!(bounds.first(), Some(&(0, 1)));
+        assert_eq!(bounds.last(), Some(&(5, 6)));
+    }
+
+    #[test]
+    fn single_value_range_yields_one_non_empty_bucket() {
+        let bounds = range_partition_bounds(7, 7, 8);
+        assert_eq!(bounds, vec![(7, 8)]);
+    }
 }
 
 impl ExecutionPlan for RemoteTableExec {
@@ -100,21 +239,36 @@ impl ExecutionPlan for RemoteTableExec {
         partition: usize,
         _context: Arc<TaskContext>,
     ) -> DFResult<SendableRecordBatchStream> {
-        assert_eq!(partition, 0);
+        let sql = self.partition_sqls[partition].clone();
         let schema = self.schema();
+        let network_time = MetricBuilder::new(&self.metrics).subset_time("network_time", partition);
         let fut = build_and_transform_stream(
             self.conn.clone(),
             self.conn_options.clone(),
-            self.sql.clone(),
+            sql,
             self.table_schema.clone(),
             self.remote_schema.clone(),
             self.projection.clone(),
             self.filters.clone(),
             self.limit,
             self.transform.clone(),
+            network_time,
         );
         let stream = futures::stream::once(fut).try_flatten();
-        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+        let stream = RecordBatchStreamAdapter::new(schema.clone(), stream);
+        Ok(Box::pin(RemoteMetricsStream {
+            inner: Box::pin(stream),
+            schema,
+            baseline: BaselineMetrics::new(&self.metrics, partition),
+            rows_fetched: MetricBuilder::new(&self.metrics).counter("remote_rows_fetched", partition),
+            batches_fetched: MetricBuilder::new(&self.metrics)
+                .counter("remote_batches_fetched", partition),
+            bytes_decoded: MetricBuilder::new(&self.metrics).counter("remote_bytes_decoded", partition),
+            time_to_first_batch: MetricBuilder::new(&self.metrics)
+                .subset_time("time_to_first_batch", partition),
+            created_at: std::time::Instant::now(),
+            saw_first_batch: false,
+        }))
     }
 
     fn with_fetch(&self, limit: Option<usize>) -> Option<Arc<dyn ExecutionPlan>> {
@@ -134,6 +288,10 @@ impl ExecutionPlan for RemoteTableExec {
                 transform: self.transform.clone(),
                 conn: self.conn.clone(),
                 plan_properties: self.plan_properties.clone(),
+                partition_sqls: self.partition_sqls.clone(),
+                metrics: ExecutionPlanMetricsSet::new(),
+                transform_name: self.transform_name.clone(),
+                statistics_cache: std::sync::OnceLock::new(),
             }))
         } else {
             None
@@ -143,6 +301,181 @@ impl ExecutionPlan for RemoteTableExec {
     fn fetch(&self) -> Option<usize> {
         self.limit
     }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> DFResult<Statistics> {
+        if let Some(stats) = self.statistics_cache.get() {
+            return Ok(stats.clone());
+        }
+        let schema = self.schema();
+        let stats = if self.conn_options.enable_statistics_probe() {
+            block_on(probe_row_count(
+                self.conn.clone(),
+                &self.conn_options,
+                &self.partition_sqls,
+            ))
+            .map(|num_rows| statistics_with_row_count(&schema, num_rows))
+            .unwrap_or_else(|_| Statistics::new_unknown(&schema))
+        } else {
+            Statistics::new_unknown(&schema)
+        };
+        // `OnceLock::set` only fails if another thread raced us to it, in which case its
+        // value is just as valid as ours, so a dropped `Err` here is fine.
+        let _ = self.statistics_cache.set(stats.clone());
+        Ok(stats)
+    }
+}
+
+/// Builds the `Statistics` `statistics()` reports once `probe_row_count` has an answer: known,
+/// if inexact, row count; everything else (column-level min/max, byte size) stays `Absent`,
+/// since the probe only ever runs a `COUNT(*)`.
+fn statistics_with_row_count(schema: &SchemaRef, num_rows: usize) -> Statistics {
+    Statistics {
+        num_rows: Precision::Inexact(num_rows),
+        ..Statistics::new_unknown(schema)
+    }
+}
+
+#[cfg(test)]
+mod statistics_tests {
+    use super::*;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn statistics_with_row_count_reports_an_inexact_row_count_only() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let stats = statistics_with_row_count(&schema, 42);
+        assert_eq!(stats.num_rows, Precision::Inexact(42));
+        assert_eq!(
+            Statistics::new_unknown(&schema).total_byte_size,
+            stats.total_byte_size
+        );
+    }
+}
+
+/// Blocks the current thread on `fut`, the way both `statistics()` and
+/// `RemoteTableExecCodec::try_decode` need to when bridging this crate's async `Connection`
+/// calls into DataFusion's synchronous `ExecutionPlan`/`PhysicalExtensionCodec` APIs.
+///
+/// Prefers `tokio::task::block_in_place` + `Handle::block_on` on a multi-thread runtime, which
+/// hands this thread's other spawned tasks off to the rest of the pool while it waits, instead
+/// of parking the tokio worker thread the way `futures::executor::block_on` would (starving the
+/// runtime under load). `block_in_place` panics on a current-thread runtime (there is no other
+/// worker to hand tasks off to), so that case — and the case where no tokio runtime is running
+/// on this thread at all — falls back to `futures::executor::block_on` instead.
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() != tokio::runtime::RuntimeFlavor::CurrentThread => {
+            tokio::task::block_in_place(|| handle.block_on(fut))
+        }
+        _ => futures::executor::block_on(fut),
+    }
+}
+
+/// Issues a `SELECT COUNT(*)` around each partition's pushed-down SQL and sums the results,
+/// giving the optimizer a real (if approximate) row count instead of treating the scan as
+/// unbounded. The count query is sent with no filters and no limit, so the plan's pushed-down
+/// `filters` and `limit` are not reflected in it. Column-level min/max bounds are left as
+/// `Precision::Absent` by the caller: most remote engines would need a second probe per column,
+/// which this opt-in, latency-sensitive path intentionally avoids.
+async fn probe_row_count(
+    conn: Arc<dyn Connection>,
+    conn_options: &ConnectionOptions,
+    partition_sqls: &[String],
+) -> DFResult<usize> {
+    let count_schema = Arc::new(datafusion::arrow::datatypes::Schema::new(vec![
+        datafusion::arrow::datatypes::Field::new(
+            "remote_table_row_count",
+            datafusion::arrow::datatypes::DataType::Int64,
+            true,
+        ),
+    ]));
+    let mut total = 0usize;
+    for sql in partition_sqls {
+        let count_sql = format!("SELECT COUNT(*) AS remote_table_row_count FROM ({sql}) t");
+        let mut stream = conn
+            .query(conn_options, &count_sql, count_schema.clone(), None, &[], None)
+            .await?;
+        if let Some(batch) = stream.try_next().await? {
+            total += row_count_from_count_batch(&batch)?;
+        }
+    }
+    Ok(total)
+}
+
+/// Reads the single `COUNT(*)` value out of the first column of a batch returned by the probe
+/// query in [`probe_row_count`], casting to `Int64` first since drivers are free to return the
+/// aggregate as whichever integer type they please. An empty array (zero rows, though a bare
+/// `COUNT(*)` should never produce that) contributes 0 rather than panicking on `value(0)`.
+fn row_count_from_count_batch(batch: &RecordBatch) -> DFResult<usize> {
+    let counts = datafusion::arrow::compute::cast(
+        batch.column(0),
+        &datafusion::arrow::datatypes::DataType::Int64,
+    )?;
+    Ok(counts
+        .as_any()
+        .downcast_ref::<datafusion::arrow::array::Int64Array>()
+        .filter(|arr| !arr.is_empty())
+        .map(|arr| arr.value(0) as usize)
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod probe_row_count_tests {
+    use super::*;
+    use datafusion::arrow::array::Int32Array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn reads_an_int64_count_directly() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "remote_table_row_count",
+            DataType::Int64,
+            true,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(datafusion::arrow::array::Int64Array::from(vec![
+                7,
+            ]))],
+        )
+        .unwrap();
+        assert_eq!(row_count_from_count_batch(&batch).unwrap(), 7);
+    }
+
+    #[test]
+    fn casts_a_narrower_integer_count_to_usize() {
+        // Some drivers return COUNT(*) as Int32 rather than Int64; the probe schema declares
+        // Int64 but `CastingStream` isn't in this path, so the cast has to happen here.
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "remote_table_row_count",
+            DataType::Int32,
+            true,
+        )]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![123]))]).unwrap();
+        assert_eq!(row_count_from_count_batch(&batch).unwrap(), 123);
+    }
+
+    #[test]
+    fn an_empty_batch_contributes_zero() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "remote_table_row_count",
+            DataType::Int64,
+            true,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(datafusion::arrow::array::Int64Array::from(
+                Vec::<i64>::new(),
+            ))],
+        )
+        .unwrap();
+        assert_eq!(row_count_from_count_batch(&batch).unwrap(), 0);
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -156,6 +489,7 @@ async fn build_and_transform_stream(
     filters: Vec<Expr>,
     limit: Option<usize>,
     transform: Option<Arc<dyn Transform>>,
+    network_time: Time,
 ) -> DFResult<SendableRecordBatchStream> {
     let transformed_table_schema = transform_schema(
         table_schema.clone(),
@@ -175,8 +509,9 @@ async fn build_and_transform_stream(
         None
     };
 
-    let stream = conn
-        .query(
+    let stream = {
+        let _timer = network_time.timer();
+        conn.query(
             &conn_options,
             &sql,
             table_schema.clone(),
@@ -184,7 +519,15 @@ async fn build_and_transform_stream(
             rewritten_filters.as_slice(),
             limit,
         )
-        .await?;
+        .await?
+    };
+
+    let target_schema = project_schema(&table_schema, projection.as_ref())?;
+    let stream: SendableRecordBatchStream = Box::pin(CastingStream::try_new(
+        stream,
+        target_schema,
+        conn_options.strict_type_coercion(),
+    )?);
 
     if let Some(transform) = transform.as_ref() {
         Ok(Box::pin(TransformStream::try_new(
@@ -199,6 +542,230 @@ async fn build_and_transform_stream(
     }
 }
 
+/// Reconciles each batch coming back from [`Connection::query`] to `target_schema`, casting a
+/// column only when its Arrow type actually differs (e.g. a driver returning `Int64` for a
+/// column declared `Int32`). This absorbs the common case of minor type drift between the
+/// remote source and the declared table schema without requiring a user-supplied [`Transform`].
+///
+/// When `strict` is `false` (the default) a column whose type cannot be cast is left as-is and
+/// the mismatch surfaces later as a normal Arrow/DataFusion schema error; when `true`, an
+/// unsupported cast fails fast with an error naming the offending column and both types.
+struct CastingStream {
+    inner: SendableRecordBatchStream,
+    target_schema: SchemaRef,
+    /// One entry per target field, in target order: the source batch column index that feeds
+    /// it, plus the cast to apply (if any) to reconcile its type to the target field's type.
+    /// Resolving by name up front means `coerce` never has to assume the inbound batch's
+    /// column order matches `target_schema`'s.
+    columns: Vec<(usize, Option<datafusion::arrow::datatypes::DataType>)>,
+    strict: bool,
+}
+
+impl CastingStream {
+    fn try_new(
+        inner: SendableRecordBatchStream,
+        target_schema: SchemaRef,
+        strict: bool,
+    ) -> DFResult<Self> {
+        let source_schema = inner.schema();
+        let mut columns = Vec::with_capacity(target_schema.fields().len());
+        for target_field in target_schema.fields() {
+            let source_idx = source_schema.index_of(target_field.name()).map_err(|_| {
+                plan_datafusion_err!(
+                    "remote stream is missing column \"{}\" required by the target schema",
+                    target_field.name()
+                )
+            })?;
+            let source_field = source_schema.field(source_idx);
+            let cast_to = if source_field.data_type() != target_field.data_type() {
+                if strict && !can_cast_types(source_field.data_type(), target_field.data_type()) {
+                    return plan_err!(
+                        "cannot coerce column \"{}\" from {:?} to {:?}",
+                        target_field.name(),
+                        source_field.data_type(),
+                        target_field.data_type()
+                    );
+                }
+                Some(target_field.data_type().clone())
+            } else {
+                None
+            };
+            columns.push((source_idx, cast_to));
+        }
+        Ok(Self {
+            inner,
+            target_schema,
+            columns,
+            strict,
+        })
+    }
+
+    fn coerce(&self, batch: RecordBatch) -> DFResult<RecordBatch> {
+        let is_identity = self
+            .columns
+            .iter()
+            .enumerate()
+            .all(|(i, (source_idx, cast_to))| *source_idx == i && cast_to.is_none());
+        if is_identity {
+            return Ok(batch);
+        }
+        let mut columns = Vec::with_capacity(self.columns.len());
+        for (source_idx, cast_to) in &self.columns {
+            let col = batch.column(*source_idx);
+            match cast_to {
+                Some(data_type) => {
+                    let cast = datafusion::arrow::compute::cast(col, data_type).map_err(|e| {
+                        datafusion::common::DataFusionError::ArrowError(Box::new(e), None)
+                    });
+                    match cast {
+                        Ok(casted) => columns.push(casted),
+                        Err(e) if self.strict => return Err(e),
+                        Err(_) => columns.push(col.clone()),
+                    }
+                }
+                None => columns.push(col.clone()),
+            }
+        }
+        Ok(RecordBatch::try_new(self.target_schema.clone(), columns)?)
+    }
+}
+
+#[cfg(test)]
+mod casting_stream_tests {
+    use super::*;
+    use datafusion::arrow::array::{Int32Array, Int64Array, StringArray};
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+
+    fn batch_stream(schema: SchemaRef, batch: RecordBatch) -> SendableRecordBatchStream {
+        Box::pin(RecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::iter(vec![Ok(batch)]),
+        ))
+    }
+
+    #[test]
+    fn coerces_type_drift_without_assuming_column_order() {
+        // Source has "b" before "a" and "b" is Int64 where the target declares Int32: this
+        // exercises both the name-based lookup and the per-column cast in the same batch.
+        let source_schema = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Int64, false),
+            Field::new("a", DataType::Utf8, false),
+        ]));
+        let source_batch = RecordBatch::try_new(
+            source_schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![10, 20])),
+                Arc::new(StringArray::from(vec!["x", "y"])),
+            ],
+        )
+        .unwrap();
+
+        let target_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+
+        let stream = CastingStream::try_new(
+            batch_stream(source_schema.clone(), source_batch.clone()),
+            target_schema.clone(),
+            false,
+        )
+        .unwrap();
+
+        let out = stream.coerce(source_batch).unwrap();
+        assert_eq!(out.schema(), target_schema);
+        let a = out
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(a.value(0), "x");
+        assert_eq!(a.value(1), "y");
+        let b = out.column(1).as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(b.value(0), 10);
+        assert_eq!(b.value(1), 20);
+    }
+
+    #[test]
+    fn identity_batches_pass_through_unchanged() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])
+                .unwrap();
+        let stream =
+            CastingStream::try_new(batch_stream(schema.clone(), batch.clone()), schema, false)
+                .unwrap();
+        let out = stream.coerce(batch.clone()).unwrap();
+        assert_eq!(out.column(0).as_ref(), batch.column(0).as_ref());
+    }
+}
+
+impl Stream for CastingStream {
+    type Item = DFResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.inner.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(batch))) => Poll::Ready(Some(self.coerce(batch))),
+            other => other,
+        }
+    }
+}
+
+impl RecordBatchStream for CastingStream {
+    fn schema(&self) -> SchemaRef {
+        self.target_schema.clone()
+    }
+}
+
+/// Wraps the remote stream to record [`BaselineMetrics`] (output rows, elapsed compute) plus
+/// counters that are specific to a remote scan: rows and batches actually returned by the
+/// remote, the decoded byte size of each batch, and the wall-clock time until the first batch
+/// arrives (dominated by remote query planning and network round-trip, as opposed to the
+/// per-batch decode/transform cost `elapsed_compute` already tracks).
+struct RemoteMetricsStream {
+    inner: Pin<Box<dyn RecordBatchStream + Send>>,
+    schema: SchemaRef,
+    baseline: BaselineMetrics,
+    rows_fetched: Count,
+    batches_fetched: Count,
+    bytes_decoded: Count,
+    time_to_first_batch: Time,
+    /// When this stream was constructed in `execute`. The remote query itself is only issued on
+    /// the first poll, so any delay before that poll is counted too. Used as the
+    /// start point for `time_to_first_batch`, which otherwise has no single moment to time from:
+    /// polling can happen any number of times (and be woken any number of times) before the
+    /// first batch actually arrives.
+    created_at: std::time::Instant,
+    saw_first_batch: bool,
+}
+
+impl Stream for RemoteMetricsStream {
+    type Item = DFResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let _compute_timer = self.baseline.elapsed_compute().timer();
+        let poll = self.inner.as_mut().poll_next(cx);
+        let poll = self.baseline.record_poll(poll);
+        if let Poll::Ready(Some(Ok(batch))) = &poll {
+            if !self.saw_first_batch {
+                self.saw_first_batch = true;
+                self.time_to_first_batch.add_duration(self.created_at.elapsed());
+            }
+            self.rows_fetched.add(batch.num_rows());
+            self.batches_fetched.add(1);
+            self.bytes_decoded.add(batch.get_array_memory_size());
+        }
+        poll
+    }
+}
+
+impl RecordBatchStream for RemoteMetricsStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
 fn rewrite_filters_column(
     filters: Vec<Expr>,
     table_schema: &SchemaRef,
@@ -224,16 +791,210 @@ fn rewrite_filters_column(
 }
 
 impl DisplayAs for RemoteTableExec {
-    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             f,
-            "RemoteTableExec: limit={:?}, filters=[{}]",
+            "RemoteTableExec: partitions={}, limit={:?}, filters=[{}]",
+            self.partition_sqls.len(),
             self.limit,
             self.filters
                 .iter()
                 .map(|e| format!("{e}"))
                 .collect::<Vec<_>>()
                 .join(", ")
-        )
+        )?;
+        if matches!(t, DisplayFormatType::Verbose) {
+            write!(f, ", sql=[{}]", self.partition_sqls.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Resolves a registered [`Transform`] by the name it was published under, so a
+/// [`RemoteTableExecCodec`] can reconstruct a `Transform` on a worker that never had a
+/// reference to the original `Arc<dyn Transform>` value.
+pub trait TransformRegistry: std::fmt::Debug + Send + Sync {
+    fn resolve(&self, name: &str) -> Option<Arc<dyn Transform>>;
+}
+
+/// Serializes the state of a [`RemoteTableExec`] needed to rebuild it on a worker node:
+/// `conn_options`, `sql`, `table_schema`, `remote_schema`, `projection`, `filters` and `limit`.
+/// `filters` round-trip through DataFusion's logical-expr protobuf encoding and `table_schema`
+/// through the Arrow IPC schema encoding; everything else is encoded with `bincode` since it is
+/// plain, crate-local configuration data rather than a plan node other engines need to
+/// understand.
+///
+/// The connection itself is never shipped: `try_decode` rebuilds `Arc<dyn Connection>` from the
+/// decoded `ConnectionOptions`, which is what lets the scan run against whichever database is
+/// reachable from the worker, rather than pulling every row back through the coordinator first.
+#[derive(Debug)]
+pub struct RemoteTableExecCodec {
+    transform_registry: Arc<dyn TransformRegistry>,
+}
+
+impl RemoteTableExecCodec {
+    pub fn new(transform_registry: Arc<dyn TransformRegistry>) -> Self {
+        Self { transform_registry }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RemoteTableExecProto {
+    conn_options: ConnectionOptions,
+    sql: String,
+    table_schema: Vec<u8>,
+    remote_schema: Option<RemoteSchemaRef>,
+    projection: Option<Vec<usize>>,
+    filters: Vec<Vec<u8>>,
+    limit: Option<usize>,
+    transform_name: Option<String>,
+}
+
+impl datafusion_proto::physical_plan::PhysicalExtensionCodec for RemoteTableExecCodec {
+    fn try_decode(
+        &self,
+        buf: &[u8],
+        inputs: &[Arc<dyn ExecutionPlan>],
+        _registry: &dyn datafusion::execution::FunctionRegistry,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if !inputs.is_empty() {
+            return plan_err!("RemoteTableExec is a leaf node and accepts no children");
+        }
+        let proto: RemoteTableExecProto = bincode::deserialize(buf)
+            .map_err(|e| datafusion::common::DataFusionError::External(Box::new(e)))?;
+        let table_schema = decode_schema(&proto.table_schema)?;
+        let filters = decode_filters(&proto.filters)?;
+        let transform = proto
+            .transform_name
+            .as_deref()
+            .map(|name| {
+                self.transform_registry.resolve(name).ok_or_else(|| {
+                    plan_datafusion_err!(
+                        "cannot decode RemoteTableExec: transform \"{name}\" is not registered \
+                         with this worker's TransformRegistry"
+                    )
+                })
+            })
+            .transpose()?;
+        // `connect` is async because most drivers negotiate the connection over the network;
+        // `try_decode` itself is synchronous, so we block on it here rather than threading a
+        // runtime handle through the `PhysicalExtensionCodec` trait.
+        let conn = block_on(proto.conn_options.connect())?;
+        let mut exec = RemoteTableExec::try_new(
+            proto.conn_options,
+            proto.sql,
+            table_schema,
+            proto.remote_schema,
+            proto.projection,
+            filters,
+            proto.limit,
+            transform,
+            conn,
+        )?;
+        if let Some(name) = proto.transform_name {
+            exec = exec.with_transform_name(name);
+        }
+        Ok(Arc::new(exec))
+    }
+
+    fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> DFResult<()> {
+        let exec = node
+            .as_any()
+            .downcast_ref::<RemoteTableExec>()
+            .ok_or_else(|| plan_datafusion_err!("RemoteTableExecCodec only supports RemoteTableExec"))?;
+        if exec.transform.is_some() && exec.transform_name.is_none() {
+            return plan_err!(
+                "cannot encode RemoteTableExec: its Transform was not registered with a \
+                 TransformRegistry (see `with_transform_name`)"
+            );
+        }
+        let proto = RemoteTableExecProto {
+            conn_options: exec.conn_options.clone(),
+            sql: exec.sql.clone(),
+            table_schema: encode_schema(&exec.table_schema),
+            remote_schema: exec.remote_schema.clone(),
+            projection: exec.projection.clone(),
+            filters: encode_filters(&exec.filters)?,
+            limit: exec.limit,
+            transform_name: exec.transform_name.clone(),
+        };
+        bincode::serialize_into(buf, &proto)
+            .map_err(|e| datafusion::common::DataFusionError::External(Box::new(e)))
+    }
+}
+
+/// Encodes an Arrow schema to the IPC wire format `decode_schema` reverses. Pulled out of
+/// `try_encode` so the round trip can be unit tested without a live `Connection`.
+fn encode_schema(schema: &SchemaRef) -> Vec<u8> {
+    datafusion::arrow::ipc::writer::schema_to_bytes(
+        schema,
+        &datafusion::arrow::ipc::writer::IpcWriteOptions::default(),
+    )
+}
+
+fn decode_schema(bytes: &[u8]) -> DFResult<SchemaRef> {
+    Ok(Arc::new(
+        datafusion::arrow::ipc::convert::try_schema_from_ipc_buffer(bytes)
+            .map_err(|e| datafusion::common::DataFusionError::External(Box::new(e)))?,
+    ))
+}
+
+/// Encodes each filter `Expr` through DataFusion's logical-expr protobuf representation, the
+/// same encoding `decode_filters` reverses. Pulled out of `try_encode` so the round trip can be
+/// unit tested without a live `Connection`.
+fn encode_filters(filters: &[Expr]) -> DFResult<Vec<Vec<u8>>> {
+    filters
+        .iter()
+        .map(|expr| {
+            let node = datafusion_proto::logical_plan::to_proto::serialize_expr(
+                expr,
+                &datafusion_proto::logical_plan::DefaultLogicalExtensionCodec {},
+            )?;
+            Ok(node.encode_to_vec())
+        })
+        .collect()
+}
+
+fn decode_filters(bytes: &[Vec<u8>]) -> DFResult<Vec<Expr>> {
+    bytes
+        .iter()
+        .map(|bytes| {
+            let node = datafusion_proto::protobuf::LogicalExprNode::decode(bytes.as_slice())
+                .map_err(|e| datafusion::common::DataFusionError::External(Box::new(e)))?;
+            datafusion_proto::logical_plan::from_proto::parse_expr(
+                &node,
+                &datafusion::execution::context::SessionContext::new(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod codec_roundtrip_tests {
+    use super::*;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::prelude::col;
+
+    #[test]
+    fn schema_round_trips_through_the_ipc_encoding() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let decoded = decode_schema(&encode_schema(&schema)).unwrap();
+        assert_eq!(decoded, schema);
+    }
+
+    #[test]
+    fn filters_round_trip_through_the_logical_expr_protobuf_encoding() {
+        let filters = vec![col("a").gt(col("b")), col("c").is_null()];
+        let decoded = decode_filters(&encode_filters(&filters).unwrap()).unwrap();
+        assert_eq!(decoded, filters);
+    }
+
+    #[test]
+    fn no_filters_round_trips_to_no_filters() {
+        let decoded = decode_filters(&encode_filters(&[]).unwrap()).unwrap();
+        assert!(decoded.is_empty());
     }
 }